@@ -39,6 +39,10 @@ contract WrappedMonero is ERC20, ERC20Permit, ReentrancyGuard {
     uint256 public constant MINT_INTENT_TIMEOUT = 2 hours;  // Intent expires after 24h
     uint256 public constant MIN_INTENT_DEPOSIT = 1e18;  // 1 DAI minimum deposit
     uint256 public constant MIN_MINT_BPS = 100;         // Minimum 1% of LP capacity (Sybil defense)
+    uint256 public constant MAX_MONERO_FUTURE_DRIFT = 2 hours; // Monero consensus future time limit
+    uint256 public constant MONERO_TIMESTAMP_WINDOW = 32; // Recent timestamps kept for the lower bound (>= 31)
+    uint256 public constant DEFAULT_MAX_ATTESTATION_AGE = 24 hours; // Max age of a posted block referenced by mint
+    uint256 public constant MIN_MAX_ATTESTATION_AGE = 1 hours; // Floor so the oracle cannot block all mints
     
     bytes32 public constant XMR_USD_PRICE_ID = 0x46b8cc9347f04391764a0361e0b17c3ba394b001e7c304f7650f6376e37c321d;
     
@@ -102,11 +106,22 @@ contract WrappedMonero is ERC20, ERC20Permit, ReentrancyGuard {
         bytes32 txMerkleRoot;      // Merkle root of transaction hashes
         bytes32 outputMerkleRoot;  // Merkle root of output data
         uint256 timestamp;
+        uint256 cumulativeDifficulty; // Monero cumulative difficulty at this block
         bool exists;
     }
     mapping(uint256 => MoneroBlockData) public moneroBlocks;
     uint256 public latestMoneroBlock;
     
+    // Monero timestamps of the last MONERO_TIMESTAMP_WINDOW posted heights,
+    // packed 4 x uint64 per slot and indexed by height % MONERO_TIMESTAMP_WINDOW
+    uint256[8] internal recentMoneroTimestamps;
+    uint256 public consecutiveMoneroBlocks;  // Length of the current run of consecutively posted heights
+    
+    // Mints must reference a block posted within maxAttestationAge, unless the
+    // oracle allows that height explicitly (disaster recovery after an outage)
+    uint256 public maxAttestationAge = DEFAULT_MAX_ATTESTATION_AGE;
+    mapping(uint256 => bool) public attestationAgeOverride;
+    
     struct MoneroTxOutput {
         bytes32 txHash;
         uint256 outputIndex;
@@ -159,6 +174,8 @@ contract WrappedMonero is ERC20, ERC20Permit, ReentrancyGuard {
     event PriceUpdated(uint256 newPrice, uint256 timestamp);
     event MoneroBlockPosted(uint256 indexed blockHeight, bytes32 indexed blockHash);
     event MoneroOutputPosted(bytes32 indexed outputId, bytes32 indexed txHash);
+    event MaxAttestationAgeUpdated(uint256 maxAge);
+    event AttestationAgeOverrideSet(uint256 indexed blockHeight, bool allowed);
     event OracleYieldClaimed(address indexed oracle, uint256 amount);
     event MintIntentCreated(bytes32 indexed intentId, address indexed user, address indexed lp, uint256 expectedAmount);
     event MintIntentFulfilled(bytes32 indexed intentId, uint256 actualAmount);
@@ -455,6 +472,11 @@ contract WrappedMonero is ERC20, ERC20Permit, ReentrancyGuard {
         
         // Verify TX exists in Monero block via Merkle proof
         require(moneroBlocks[blockHeight].exists, "Block not posted");
+        require(
+            block.timestamp <= moneroBlocks[blockHeight].timestamp + maxAttestationAge ||
+                attestationAgeOverride[blockHeight],
+            "Attestation too old"
+        );
         require(
             verifyTxInBlock(output.txHash, blockHeight, txMerkleProof, txIndex),
             "TX not in block"
@@ -594,32 +616,98 @@ contract WrappedMonero is ERC20, ERC20Permit, ReentrancyGuard {
      * @param blockHash Block hash
      * @param txMerkleRoot Merkle root of all TX hashes in block
      * @param outputMerkleRoot Merkle root of all output data in block
+     * @param moneroTimestamp Monero block header timestamp
+     * @param cumulativeDifficulty Monero cumulative difficulty at this block
+     * @dev The exact 60-block median-time-past rule is enforced by the oracle service;
+     *      on-chain only the cheaper lower bound from moneroTimestampFloor is checked
      */
     function postMoneroBlock(
         uint256 blockHeight,
         bytes32 blockHash,
         bytes32 txMerkleRoot,
-        bytes32 outputMerkleRoot
+        bytes32 outputMerkleRoot,
+        uint256 moneroTimestamp,
+        uint256 cumulativeDifficulty
     ) external onlyOracle {
         require(blockHeight > latestMoneroBlock, "Height must increase");
         require(!moneroBlocks[blockHeight].exists, "Block exists");
+        require(moneroTimestamp <= block.timestamp + MAX_MONERO_FUTURE_DRIFT, "Timestamp too far in future");
+        require(moneroTimestamp >= moneroTimestampFloor(blockHeight), "Timestamp below recent minimum");
+        // Zero for the first block after deployment, so any positive difficulty passes
+        require(
+            cumulativeDifficulty > moneroBlocks[latestMoneroBlock].cumulativeDifficulty,
+            "Difficulty must increase"
+        );
         
         moneroBlocks[blockHeight] = MoneroBlockData({
             blockHash: blockHash,
             txMerkleRoot: txMerkleRoot,
             outputMerkleRoot: outputMerkleRoot,
             timestamp: block.timestamp,
+            cumulativeDifficulty: cumulativeDifficulty,
             exists: true
         });
         
+        _recordMoneroTimestamp(blockHeight, moneroTimestamp);
+        consecutiveMoneroBlocks = blockHeight == latestMoneroBlock + 1 ? consecutiveMoneroBlocks + 1 : 1;
         latestMoneroBlock = blockHeight;
         emit MoneroBlockPosted(blockHeight, blockHash);
     }
     
+    /**
+     * @notice Lower bound that every valid Monero timestamp at `blockHeight` satisfies
+     * @dev Monero rejects timestamps below the median of the previous 60 blocks. At least
+     *      30 of those 60 are <= the median, so the minimum of any 31 of them is a bound.
+     *      Only applies when the last MONERO_TIMESTAMP_WINDOW posted heights are
+     *      consecutive and end right below `blockHeight`; returns 0 otherwise
+     */
+    function moneroTimestampFloor(uint256 blockHeight) public view returns (uint256 floor) {
+        if (blockHeight != latestMoneroBlock + 1 || consecutiveMoneroBlocks < MONERO_TIMESTAMP_WINDOW) {
+            return 0;
+        }
+        
+        floor = type(uint64).max;
+        for (uint256 i = 0; i < MONERO_TIMESTAMP_WINDOW / 4; i++) {
+            uint256 packed = recentMoneroTimestamps[i];
+            for (uint256 j = 0; j < 4; j++) {
+                uint256 ts = uint64(packed >> (j * 64));
+                if (ts < floor) floor = ts;
+            }
+        }
+    }
+    
+    function _recordMoneroTimestamp(uint256 blockHeight, uint256 moneroTimestamp) internal {
+        // Fits in uint64: bounded by block.timestamp + MAX_MONERO_FUTURE_DRIFT
+        uint256 index = blockHeight % MONERO_TIMESTAMP_WINDOW;
+        uint256 shift = (index % 4) * 64;
+        uint256 packed = recentMoneroTimestamps[index / 4] & ~(uint256(type(uint64).max) << shift);
+        recentMoneroTimestamps[index / 4] = packed | (moneroTimestamp << shift);
+    }
+    
     function transferOracle(address newOracle) external onlyOracle {
         oracle = newOracle;
     }
     
+    /**
+     * @notice Set the maximum age of a posted block that mint accepts
+     * @param maxAge Seconds since the block was posted, at least MIN_MAX_ATTESTATION_AGE
+     */
+    function setMaxAttestationAge(uint256 maxAge) external onlyOracle {
+        require(maxAge >= MIN_MAX_ATTESTATION_AGE, "Max age too short");
+        maxAttestationAge = maxAge;
+        emit MaxAttestationAgeUpdated(maxAge);
+    }
+    
+    /**
+     * @notice Allow or disallow mints against a posted block regardless of its age
+     * @dev For disaster recovery, e.g. deposits stranded by a long mint outage
+     */
+    function setAttestationAgeOverride(uint256 blockHeight, bool allowed) external onlyOracle {
+        require(moneroBlocks[blockHeight].exists, "Block not posted");
+        attestationAgeOverride[blockHeight] = allowed;
+        emit AttestationAgeOverrideSet(blockHeight, allowed);
+    }
+    
     /**
      * @notice Oracle claims yield from sDAI vault
      * @dev Oracle gets all interest earned on LP collateral
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+import "@pythnetwork/pyth-sdk-solidity/PythStructs.sol";
+
+/**
+ * @title MockPyth
+ * @notice Test-only Pyth stand-in returning a fixed, always-fresh price for any feed
+ */
+contract MockPyth {
+    int64 public price;
+    int32 public expo;
+
+    constructor(int64 _price, int32 _expo) {
+        price = _price;
+        expo = _expo;
+    }
+
+    function getPriceUnsafe(bytes32) external view returns (PythStructs.Price memory) {
+        return PythStructs.Price({price: price, conf: 0, expo: expo, publishTime: block.timestamp});
+    }
+
+    function getPriceNoOlderThan(bytes32, uint256) external view returns (PythStructs.Price memory) {
+        return PythStructs.Price({price: price, conf: 0, expo: expo, publishTime: block.timestamp});
+    }
+
+    function getUpdateFee(bytes[] calldata) external pure returns (uint256) {
+        return 0;
+    }
+
+    function updatePriceFeeds(bytes[] calldata) external payable {}
+}
@@ -7,7 +7,12 @@
  * Runs every 2 minutes to keep the contract synchronized with Monero chain
  * 
  * Usage:
- *   node oracle/monero-oracle.js
+ *   node oracle/monero-oracle.js [--drain | --verify]
+ * 
+ *   --drain   On SIGINT/SIGTERM, finish posting every block detected by the
+ *             current poll before exiting (default: stop after the block in flight)
+ *   --verify  Post nothing; instead keep re-fetching random posted heights from
+ *             the VERIFY_RPC_URLS nodes and report where they diverge from on-chain data
  * 
  * Environment variables:
  *   ORACLE_PRIVATE_KEY - Private key of oracle account
@@ -15,6 +20,11 @@
  *   RPC_URL - Ethereum RPC URL (default: Base Sepolia)
  *   MONERO_RPC_URL - Monero RPC URL (default: mainnet)
  *   INTERVAL_MS - Polling interval in milliseconds (default: 120000 = 2 min)
+ *   MONERO_RPC_TIMEOUT_MS - Timeout for each Monero RPC request (default: 20000)
+ *   MAX_FUTURE_DRIFT_SEC - Max seconds a block timestamp may be ahead of local time (default/max: 7200)
+ *   VERIFY_RPC_URLS - Comma-separated Monero RPC URLs checked by --verify (default: MONERO_RPC_URL)
+ *   VERIFY_SAMPLES - Heights checked per --verify round (default: 5)
+ *   VERIFY_LOOKBACK_BLOCKS - --verify samples from this many heights below the latest posted (default: 5000)
  */
 
 require('dotenv').config();
@@ -38,19 +48,76 @@ const config = {
     rpcUrl: process.env.RPC_URL || 'http://localhost:8545',
     moneroRpcUrl: process.env.MONERO_RPC_URL || 'https://stagenet.xmr.ditatompel.com',
     intervalMs: parseInt(process.env.INTERVAL_MS || '120000'), // 2 minutes
+    moneroRpcTimeoutMs: parseInt(process.env.MONERO_RPC_TIMEOUT_MS || '20000'), // Bounds a stalled node, and so a stop
+    maxFutureDriftSec: parseInt(process.env.MAX_FUTURE_DRIFT_SEC || '7200'), // Monero consensus limit: 2 hours
+    verifyRpcUrls: (process.env.VERIFY_RPC_URLS || process.env.MONERO_RPC_URL || 'https://stagenet.xmr.ditatompel.com')
+        .split(',').map(url => url.trim()).filter(url => url.length > 0),
+    verifySamples: parseInt(process.env.VERIFY_SAMPLES || '5'),
+    verifyLookback: parseInt(process.env.VERIFY_LOOKBACK_BLOCKS || '5000'),
 };
 
-// Validate configuration
-if (!config.oraclePrivateKey) {
-    console.error('❌ ORACLE_PRIVATE_KEY not set in .env and no deployment.json found');
-    console.error('   Run: npx hardhat run scripts/deploy_oracle_test.js --network localhost');
-    process.exit(1);
-}
+const verifyMode = process.argv.includes('--verify');
+
+// Must match WrappedMonero.MAX_MONERO_FUTURE_DRIFT; a larger oracle limit would accept
+// headers the contract then reverts on
+const ONCHAIN_MAX_FUTURE_DRIFT_SEC = 7200;
 
-if (!config.bridgeAddress) {
-    console.error('❌ BRIDGE_ADDRESS not set in .env and no deployment.json found');
-    console.error('   Run: npx hardhat run scripts/deploy_oracle_test.js --network localhost');
-    process.exit(1);
+// Monero consensus: a block timestamp must not be below the median of the previous 60 blocks
+const TIMESTAMP_CHECK_WINDOW = 60;
+
+// Header validation error codes
+const HeaderError = {
+    NON_SEQUENTIAL_HEIGHT: 'NON_SEQUENTIAL_HEIGHT',
+    PREV_HASH_MISMATCH: 'PREV_HASH_MISMATCH',
+    TIMESTAMP_BELOW_MEDIAN: 'TIMESTAMP_BELOW_MEDIAN',
+    TIMESTAMP_TOO_FAR_IN_FUTURE: 'TIMESTAMP_TOO_FAR_IN_FUTURE',
+    DIFFICULTY_NOT_INCREASING: 'DIFFICULTY_NOT_INCREASING',
+};
+
+// Shutdown state, shared between the signal handlers and the oracle loop
+const shutdown = {
+    requested: false,
+    drain: process.argv.includes('--drain'),
+};
+let inFlightPoll = null;
+let pollTimer = null;
+
+// Validate configuration
+function validateConfig() {
+    // --verify only reads the contract, so it needs no key
+    if (!config.oraclePrivateKey && !verifyMode) {
+        console.error('❌ ORACLE_PRIVATE_KEY not set in .env and no deployment.json found');
+        console.error('   Run: npx hardhat run scripts/deploy_oracle_test.js --network localhost');
+        process.exit(1);
+    }
+    
+    if (!config.bridgeAddress) {
+        console.error('❌ BRIDGE_ADDRESS not set in .env and no deployment.json found');
+        console.error('   Run: npx hardhat run scripts/deploy_oracle_test.js --network localhost');
+        process.exit(1);
+    }
+    
+    if (!Number.isInteger(config.maxFutureDriftSec) || config.maxFutureDriftSec < 0 ||
+        config.maxFutureDriftSec > ONCHAIN_MAX_FUTURE_DRIFT_SEC) {
+        console.error(`❌ MAX_FUTURE_DRIFT_SEC must be between 0 and ${ONCHAIN_MAX_FUTURE_DRIFT_SEC} (the on-chain limit)`);
+        process.exit(1);
+    }
+    
+    if (!Number.isInteger(config.moneroRpcTimeoutMs) || config.moneroRpcTimeoutMs <= 0) {
+        console.error('❌ MONERO_RPC_TIMEOUT_MS must be a positive number of milliseconds');
+        process.exit(1);
+    }
+    
+    if (verifyMode && (config.verifyRpcUrls.length === 0 ||
+        !(config.verifySamples > 0) || !(config.verifyLookback > 0))) {
+        console.error('❌ --verify needs VERIFY_RPC_URLS, and positive VERIFY_SAMPLES and VERIFY_LOOKBACK_BLOCKS');
+        process.exit(1);
+    }
+    
+    if (verifyMode && shutdown.drain) {
+        console.error('❌ --drain and --verify cannot be combined');
+        process.exit(1);
+    }
 }
 
 // Monero RPC helper - Get block header
@@ -64,7 +131,7 @@ async function getMoneroBlockHeader(height = null) {
             id: '0',
             method,
             params
-        });
+        }, { timeout: config.moneroRpcTimeoutMs });
         
         if (response.data.error) {
             throw new Error(response.data.error.message);
@@ -77,93 +144,117 @@ async function getMoneroBlockHeader(height = null) {
     }
 }
 
-// Get full block with transactions (for Merkle root)
-async function getMoneroBlock(height) {
+// Monero RPC helper - Get a contiguous range of block headers
+async function getMoneroBlockHeadersRange(startHeight, endHeight) {
     try {
         const response = await axios.post(config.moneroRpcUrl + '/json_rpc', {
             jsonrpc: '2.0',
             id: '0',
-            method: 'get_block',
-            params: { height }
-        });
+            method: 'get_block_headers_range',
+            params: { start_height: startHeight, end_height: endHeight }
+        }, { timeout: config.moneroRpcTimeoutMs });
         
         if (response.data.error) {
             throw new Error(response.data.error.message);
         }
         
-        return response.data.result;
+        return response.data.result.headers;
     } catch (error) {
         console.error('❌ Monero RPC error:', error.message);
         throw error;
     }
 }
 
-// Extract outputs from block
-async function extractOutputsFromBlock(blockHeight) {
+// Get full block with transactions (for Merkle root)
+async function getMoneroBlock(height, rpcUrl = config.moneroRpcUrl) {
     try {
-        // Get full block
-        const blockData = await getMoneroBlock(blockHeight);
-        const blockJson = JSON.parse(blockData.json);
-        const txHashes = blockJson.tx_hashes || [];
-        
-        if (txHashes.length === 0) {
-            console.log(`   No transactions in block ${blockHeight}`);
-            return [];
-        }
-        
-        console.log(`   Fetching ${txHashes.length} transaction(s) from block...`);
-        
-        // Fetch all transactions
-        const response = await axios.post(config.moneroRpcUrl + '/get_transactions', {
-            txs_hashes: txHashes,
-            decode_as_json: true
-        });
+        const response = await axios.post(rpcUrl + '/json_rpc', {
+            jsonrpc: '2.0',
+            id: '0',
+            method: 'get_block',
+            params: { height }
+        }, { timeout: config.moneroRpcTimeoutMs });
         
-        if (response.data.status !== 'OK' || !response.data.txs) {
-            console.error('   ⚠️  Failed to fetch transactions');
-            return [];
+        if (response.data.error) {
+            throw new Error(response.data.error.message);
         }
         
-        const allOutputs = [];
+        return response.data.result;
+    } catch (error) {
+        console.error('❌ Monero RPC error:', error.message);
+        throw error;
+    }
+}
+
+// Fetch the outputs of every transaction in a block, throwing on any RPC failure
+async function fetchOutputsFromBlock(blockHeight, rpcUrl = config.moneroRpcUrl) {
+    // Get full block
+    const blockData = await getMoneroBlock(blockHeight, rpcUrl);
+    const blockJson = JSON.parse(blockData.json);
+    const txHashes = blockJson.tx_hashes || [];
+    
+    if (txHashes.length === 0) {
+        console.log(`   No transactions in block ${blockHeight}`);
+        return [];
+    }
+    
+    console.log(`   Fetching ${txHashes.length} transaction(s) from block...`);
+    
+    // Fetch all transactions
+    const response = await axios.post(rpcUrl + '/get_transactions', {
+        txs_hashes: txHashes,
+        decode_as_json: true
+    }, { timeout: config.moneroRpcTimeoutMs });
+    
+    if (response.data.status !== 'OK' || !response.data.txs) {
+        throw new Error('Failed to fetch transactions');
+    }
+    
+    const allOutputs = [];
+    
+    // Extract outputs from each transaction
+    for (const tx of response.data.txs) {
+        const txJson = JSON.parse(tx.as_json);
+        const txHash = tx.tx_hash;
         
-        // Extract outputs from each transaction
-        for (const tx of response.data.txs) {
-            const txJson = JSON.parse(tx.as_json);
-            const txHash = tx.tx_hash;
-            
-            // Extract each output
-            if (txJson.vout && txJson.rct_signatures) {
-                for (let i = 0; i < txJson.vout.length; i++) {
-                    const output = txJson.vout[i];
-                    const ecdh = txJson.rct_signatures.ecdhInfo ? txJson.rct_signatures.ecdhInfo[i] : null;
-                    const commitment = txJson.rct_signatures.outPk ? txJson.rct_signatures.outPk[i] : null;
-                    
-                    // Handle both old format (target.key) and new format (target.tagged_key.key)
-                    let outputPubKey = null;
-                    if (output.target) {
-                        if (output.target.key) {
-                            outputPubKey = output.target.key;
-                        } else if (output.target.tagged_key && output.target.tagged_key.key) {
-                            outputPubKey = output.target.tagged_key.key;
-                        }
-                    }
-                    
-                    if (ecdh && commitment && outputPubKey) {
-                        allOutputs.push({
-                            txHash: '0x' + txHash,
-                            outputIndex: i,
-                            ecdhAmount: '0x' + ecdh.amount,
-                            outputPubKey: '0x' + outputPubKey,
-                            commitment: '0x' + commitment
-                        });
+        // Extract each output
+        if (txJson.vout && txJson.rct_signatures) {
+            for (let i = 0; i < txJson.vout.length; i++) {
+                const output = txJson.vout[i];
+                const ecdh = txJson.rct_signatures.ecdhInfo ? txJson.rct_signatures.ecdhInfo[i] : null;
+                const commitment = txJson.rct_signatures.outPk ? txJson.rct_signatures.outPk[i] : null;
+                
+                // Handle both old format (target.key) and new format (target.tagged_key.key)
+                let outputPubKey = null;
+                if (output.target) {
+                    if (output.target.key) {
+                        outputPubKey = output.target.key;
+                    } else if (output.target.tagged_key && output.target.tagged_key.key) {
+                        outputPubKey = output.target.tagged_key.key;
                     }
                 }
+                
+                if (ecdh && commitment && outputPubKey) {
+                    allOutputs.push({
+                        txHash: '0x' + txHash,
+                        outputIndex: i,
+                        ecdhAmount: '0x' + ecdh.amount,
+                        outputPubKey: '0x' + outputPubKey,
+                        commitment: '0x' + commitment
+                    });
+                }
             }
         }
-        
-        console.log(`   Extracted ${allOutputs.length} outputs from ${txHashes.length} transaction(s)`);
-        return allOutputs;
-        
+    }
+    
+    console.log(`   Extracted ${allOutputs.length} outputs from ${txHashes.length} transaction(s)`);
+    return allOutputs;
+}
+
+// Extract outputs from block
+async function extractOutputsFromBlock(blockHeight) {
+    try {
+        return await fetchOutputsFromBlock(blockHeight);
     } catch (error) {
         console.error(`   ⚠️  Error extracting outputs: ${error.message}`);
         return [];
@@ -261,8 +352,101 @@ function computeOutputMerkleRoot(outputs) {
     return '0x' + level[0].toString('hex');
 }
 
+// Cumulative difficulty can exceed 2^53, prefer the wide hex form when present
+function cumulativeDifficulty(header) {
+    return BigInt(header.wide_cumulative_difficulty || header.cumulative_difficulty);
+}
+
+function median(values) {
+    const sorted = [...values].sort((a, b) => a - b);
+    const mid = Math.floor(sorted.length / 2);
+    return sorted.length % 2 === 0 ? Math.floor((sorted[mid - 1] + sorted[mid]) / 2) : sorted[mid];
+}
+
+// Validate a header against the previously accepted headers (oldest first)
+// Returns null if valid, otherwise { code, message }
+function validateBlockHeader(
+    header,
+    previousHeaders,
+    nowSec = Math.floor(Date.now() / 1000),
+    maxFutureDriftSec = config.maxFutureDriftSec
+) {
+    if (header.timestamp > nowSec + maxFutureDriftSec) {
+        return {
+            code: HeaderError.TIMESTAMP_TOO_FAR_IN_FUTURE,
+            message: `timestamp ${header.timestamp} is more than ${maxFutureDriftSec}s ahead of local time ${nowSec}`
+        };
+    }
+    
+    if (previousHeaders.length === 0) {
+        return null;
+    }
+    
+    const prev = previousHeaders[previousHeaders.length - 1];
+    
+    if (header.height !== prev.height + 1) {
+        return {
+            code: HeaderError.NON_SEQUENTIAL_HEIGHT,
+            message: `expected height ${prev.height + 1}, got ${header.height}`
+        };
+    }
+    
+    if (header.prev_hash !== prev.hash) {
+        return {
+            code: HeaderError.PREV_HASH_MISMATCH,
+            message: `prev_hash ${header.prev_hash} does not match block ${prev.height} hash ${prev.hash}`
+        };
+    }
+    
+    if (cumulativeDifficulty(header) <= cumulativeDifficulty(prev)) {
+        return {
+            code: HeaderError.DIFFICULTY_NOT_INCREASING,
+            message: `cumulative difficulty did not increase over block ${prev.height}`
+        };
+    }
+    
+    // Monero only enforces the median rule once a full window is available
+    if (previousHeaders.length >= TIMESTAMP_CHECK_WINDOW) {
+        const window = previousHeaders.slice(-TIMESTAMP_CHECK_WINDOW).map(h => h.timestamp);
+        const mtp = median(window);
+        // Matches check_block_timestamp: only strictly-below-median is rejected
+        if (header.timestamp < mtp) {
+            return {
+                code: HeaderError.TIMESTAMP_BELOW_MEDIAN,
+                message: `timestamp ${header.timestamp} is below median-time-past ${mtp}`
+            };
+        }
+    }
+    
+    return null;
+}
+
+// Validate that a header extends the parent block already stored on-chain
+// `storedParent` is the contract's moneroBlocks(header.height - 1) entry
+// Returns null if valid (or the parent was never posted), otherwise { code, message }
+function validateOnChainLink(header, storedParent) {
+    if (!storedParent.exists) {
+        return null;
+    }
+    
+    if (('0x' + header.prev_hash).toLowerCase() !== storedParent.blockHash.toLowerCase()) {
+        return {
+            code: HeaderError.PREV_HASH_MISMATCH,
+            message: `prev_hash 0x${header.prev_hash} does not match on-chain block ${header.height - 1} hash ${storedParent.blockHash}`
+        };
+    }
+    
+    return null;
+}
+
+// Fields of a stored on-chain block that differ from `block`, empty if all match
+function diffStoredBlock(stored, block) {
+    return ['blockHash', 'txMerkleRoot', 'outputMerkleRoot']
+        .filter(field => stored[field].toLowerCase() !== block[field].toLowerCase());
+}
+
 // Post block to contract
-async function postBlock(contract, blockHeight, blockHash, txMerkleRoot, outputMerkleRoot) {
+async function postBlock(contract, blockHeight, blockHash, txMerkleRoot, outputMerkleRoot, header) {
     try {
         console.log(`\n📤 Posting block ${blockHeight} to contract...`);
         console.log(`   Hash: ${blockHash}`);
@@ -273,7 +457,9 @@ async function postBlock(contract, blockHeight, blockHash, txMerkleRoot, outputM
             blockHeight,
             blockHash,
             txMerkleRoot,
-            outputMerkleRoot
+            outputMerkleRoot,
+            header.timestamp,
+            cumulativeDifficulty(header)
         );
         
         console.log(`   TX: ${tx.hash}`);
@@ -286,7 +472,22 @@ async function postBlock(contract, blockHeight, blockHash, txMerkleRoot, outputM
         
         return receipt;
     } catch (error) {
-        if (error.message.includes('Block already posted')) {
+        // Contract heights are strictly increasing, so a replayed post (e.g. an RPC
+        // retry after the original tx was mined) reverts. It is only safe to skip if
+        // the stored block is exactly what this oracle tried to post. The contract's
+        // 'Block exists' check can never fire first, so only this revert is matched
+        if (error.message.includes('Height must increase')) {
+            const stored = await contract.moneroBlocks(blockHeight);
+            const matches = stored.exists &&
+                diffStoredBlock(stored, { blockHash, txMerkleRoot, outputMerkleRoot }).length === 0;
+            
+            if (!matches) {
+                throw new Error(
+                    `Block ${blockHeight} conflicts with on-chain data ` +
+                    `(stored hash ${stored.exists ? stored.blockHash : 'none'}, posted ${blockHash})`
+                );
+            }
+            
             console.log(`   ⚠️  Block ${blockHeight} already posted`);
             return null;
         }
@@ -294,6 +495,87 @@ async function postBlock(contract, blockHeight, blockHash, txMerkleRoot, outputM
     }
 }
 
+// Post heights fromHeight..toHeight in order, stopping between blocks when a
+// non-drain shutdown is requested. `storedParent` is the on-chain block at
+// fromHeight - 1 and `recentHeaders` the accepted headers preceding fromHeight.
+// Returns the updated header window (emptied after a rejected header) and the
+// last height posted, or null if none was. `deps` lets tests replace the RPC,
+// contract and shutdown dependencies
+async function postPendingBlocks(contract, { fromHeight, toHeight, storedParent, recentHeaders }, deps = {}) {
+    const {
+        fetchBlock = getMoneroBlock,
+        fetchOutputs = extractOutputsFromBlock,
+        post = postBlock,
+        shutdownState = shutdown,
+    } = deps;
+    
+    let headers = [...recentHeaders];
+    let lastPosted = null;
+    
+    for (let height = fromHeight; height <= toHeight; height++) {
+        // Never abandon a block mid-post; only stop between blocks
+        if (shutdownState.requested && !shutdownState.drain) {
+            console.log(`\n   🛑 Shutdown requested, stopping before block ${height}`);
+            break;
+        }
+        
+        console.log(`\n   📦 Processing block ${height}...`);
+        
+        // Get full block with transactions
+        const blockData = await fetchBlock(height);
+        const blockJson = JSON.parse(blockData.json);
+        const txHashes = blockJson.tx_hashes || [];
+        const blockHashForHeight = '0x' + blockData.block_header.hash;
+        
+        // The first block must extend what is already on-chain. This does not
+        // depend on the node, so a forked node keeps the oracle halted
+        if (height === fromHeight) {
+            const linkError = validateOnChainLink(blockData.block_header, storedParent);
+            if (linkError) {
+                console.error(`      ❌ Header rejected [${linkError.code}]: ${linkError.message}`);
+                console.error(`      Monero node disagrees with the chain posted on-chain (reorg or bad node)`);
+                console.error(`      Halting until the node follows the posted chain - operator intervention required`);
+                headers = [];
+                break;
+            }
+        }
+        
+        // Reject headers that violate Monero timestamp/difficulty rules
+        const headerError = validateBlockHeader(blockData.block_header, headers);
+        if (headerError) {
+            console.error(`      ❌ Header rejected [${headerError.code}]: ${headerError.message}`);
+            console.error(`      Halting until the next poll`);
+            headers = [];
+            break;
+        }
+        
+        console.log(`      Transactions: ${txHashes.length}`);
+        
+        // Compute TX Merkle root
+        const txMerkleRoot = computeTxMerkleRoot(txHashes);
+        console.log(`      TX Merkle root: ${txMerkleRoot}`);
+        
+        // Extract outputs from block
+        const outputs = await fetchOutputs(height);
+        console.log(`      Outputs: ${outputs.length}`);
+        
+        // Compute output Merkle root
+        const outputMerkleRoot = computeOutputMerkleRoot(outputs);
+        console.log(`      Output Merkle root: ${outputMerkleRoot}`);
+        
+        // Post to contract
+        await post(contract, height, blockHashForHeight, txMerkleRoot, outputMerkleRoot, blockData.block_header);
+        lastPosted = height;
+        
+        headers.push(blockData.block_header);
+        if (headers.length > TIMESTAMP_CHECK_WINDOW) {
+            headers.shift();
+        }
+    }
+    
+    return { recentHeaders: headers, lastPostedBlock: lastPosted };
+}
+
 // Main oracle loop
 async function runOracle() {
     console.log('🔮 Monero Oracle Service Starting...\n');
@@ -336,7 +618,21 @@ async function runOracle() {
     // Main loop
     let lastPostedBlock = 0;
     
+    // Recently accepted headers (oldest first), used for header validation
+    let recentHeaders = [];
+    
+    // Load the headers preceding `nextHeight` so the median window is full
+    async function loadRecentHeaders(nextHeight) {
+        const endHeight = nextHeight - 1;
+        const startHeight = Math.max(0, endHeight - TIMESTAMP_CHECK_WINDOW + 1);
+        recentHeaders = endHeight >= 0 ? await getMoneroBlockHeadersRange(startHeight, endHeight) : [];
+    }
+    
     async function poll() {
+        if (shutdown.requested) {
+            return;
+        }
+        
         try {
             console.log(`\n[${new Date().toISOString()}] 🔍 Checking Monero blockchain...`);
             
@@ -357,33 +653,22 @@ async function runOracle() {
                 const blocksToPost = blockHeight - Number(latestPosted);
                 console.log(`   📊 ${blocksToPost} new block(s) detected!`);
                 
-                // Post each block sequentially
-                for (let height = Number(latestPosted) + 1; height <= blockHeight; height++) {
-                    console.log(`\n   📦 Processing block ${height}...`);
-                    
-                    // Get full block with transactions
-                    const blockData = await getMoneroBlock(height);
-                    const blockJson = JSON.parse(blockData.json);
-                    const txHashes = blockJson.tx_hashes || [];
-                    const blockHashForHeight = '0x' + blockData.block_header.hash;
-                    
-                    console.log(`      Transactions: ${txHashes.length}`);
-                    
-                    // Compute TX Merkle root
-                    const txMerkleRoot = computeTxMerkleRoot(txHashes);
-                    console.log(`      TX Merkle root: ${txMerkleRoot}`);
-                    
-                    // Extract outputs from block
-                    const outputs = await extractOutputsFromBlock(height);
-                    console.log(`      Outputs: ${outputs.length}`);
-                    
-                    // Compute output Merkle root
-                    const outputMerkleRoot = computeOutputMerkleRoot(outputs);
-                    console.log(`      Output Merkle root: ${outputMerkleRoot}`);
-                    
-                    // Post to contract
-                    await postBlock(contract, height, blockHashForHeight, txMerkleRoot, outputMerkleRoot);
-                    lastPostedBlock = height;
+                const nextHeight = Number(latestPosted) + 1;
+                const storedParent = await contract.moneroBlocks(latestPosted);
+                const lastKnown = recentHeaders[recentHeaders.length - 1];
+                if (!lastKnown || lastKnown.height !== nextHeight - 1) {
+                    await loadRecentHeaders(nextHeight);
+                }
+                
+                const result = await postPendingBlocks(contract, {
+                    fromHeight: nextHeight,
+                    toHeight: blockHeight,
+                    storedParent,
+                    recentHeaders,
+                });
+                recentHeaders = result.recentHeaders;
+                if (result.lastPostedBlock !== null) {
+                    lastPostedBlock = result.lastPostedBlock;
                 }
             } else {
                 console.log(`   ✅ Already up to date`);
@@ -395,27 +680,296 @@ async function runOracle() {
         }
     }
     
+    await startPolling(poll);
+}
+
+// Run `poll` now and then every intervalMs until shutdown
+async function startPolling(poll) {
+    async function runPoll() {
+        inFlightPoll = poll();
+        await inFlightPoll;
+        inFlightPoll = null;
+    }
+    
+    // Schedule the next poll only after the previous one finished, so polls never overlap
+    function scheduleNextPoll() {
+        if (shutdown.requested) {
+            return;
+        }
+        pollTimer = setTimeout(async () => {
+            await runPoll();
+            scheduleNextPoll();
+        }, config.intervalMs);
+    }
+    
     // Initial poll
-    await poll();
+    await runPoll();
     
-    // Set up interval
+    // Set up polling
     console.log(`\n⏰ Polling every ${config.intervalMs / 1000}s...`);
-    setInterval(poll, config.intervalMs);
+    scheduleNextPoll();
+}
+
+// Pick up to `count` distinct heights in (latestPosted - lookback, latestPosted]
+function sampleHeights(latestPosted, lookback, count, random = Math.random) {
+    const lowest = Math.max(1, latestPosted - lookback + 1);
+    const span = latestPosted - lowest + 1;
+    const heights = new Set();
+    while (span > 0 && heights.size < Math.min(count, span)) {
+        heights.add(lowest + Math.floor(random() * span));
+    }
+    return [...heights].sort((a, b) => a - b);
+}
+
+// Recompute the block at `height` from each node and compare it with the stored block.
+// Returns { height, exists, nodes: [{ rpcUrl, mismatches, error }] }; `exists` is false
+// (and no node is queried) when the height was never posted
+async function verifyHeight(contract, height, rpcUrls, deps = {}) {
+    const {
+        fetchBlock = getMoneroBlock,
+        fetchOutputs = fetchOutputsFromBlock,
+    } = deps;
+    
+    const stored = await contract.moneroBlocks(height);
+    if (!stored.exists) {
+        return { height, exists: false, nodes: [] };
+    }
+    
+    const nodes = [];
+    for (const rpcUrl of rpcUrls) {
+        try {
+            const blockData = await fetchBlock(height, rpcUrl);
+            const txHashes = JSON.parse(blockData.json).tx_hashes || [];
+            const outputs = await fetchOutputs(height, rpcUrl);
+            const mismatches = diffStoredBlock(stored, {
+                blockHash: '0x' + blockData.block_header.hash,
+                txMerkleRoot: computeTxMerkleRoot(txHashes),
+                outputMerkleRoot: computeOutputMerkleRoot(outputs),
+            });
+            nodes.push({ rpcUrl, mismatches, error: null });
+        } catch (error) {
+            nodes.push({ rpcUrl, mismatches: [], error: error.message });
+        }
+    }
+    return { height, exists: true, nodes };
+}
+
+// Create empty --verify counters, kept for the lifetime of the process
+function createVerifyMetrics(rpcUrls) {
+    const metrics = { rounds: 0, skipped: 0, nodes: {} };
+    for (const rpcUrl of rpcUrls) {
+        metrics.nodes[rpcUrl] = {
+            checked: 0,
+            matched: 0,
+            diverged: 0,
+            errors: 0,
+            fields: { blockHash: 0, txMerkleRoot: 0, outputMerkleRoot: 0 },
+        };
+    }
+    return metrics;
+}
+
+// Add the verifyHeight results of one round to `metrics`
+function recordVerifyResults(metrics, results) {
+    metrics.rounds++;
+    for (const result of results) {
+        if (!result.exists) {
+            metrics.skipped++;
+            continue;
+        }
+        for (const node of result.nodes) {
+            const counters = metrics.nodes[node.rpcUrl];
+            counters.checked++;
+            if (node.error) {
+                counters.errors++;
+            } else if (node.mismatches.length > 0) {
+                counters.diverged++;
+                for (const field of node.mismatches) {
+                    counters.fields[field]++;
+                }
+            } else {
+                counters.matched++;
+            }
+        }
+    }
+    return metrics;
+}
+
+// Soak mode: re-verify random posted heights against independent nodes without posting
+async function runVerifier() {
+    console.log('🔮 Monero Oracle Verifier Starting...\n');
+    console.log('Configuration:');
+    console.log(`   Monero RPCs: ${config.verifyRpcUrls.join(', ')}`);
+    console.log(`   Ethereum RPC: ${config.rpcUrl}`);
+    console.log(`   WrappedMonero: ${config.bridgeAddress}`);
+    console.log(`   Samples: ${config.verifySamples} per round from the last ${config.verifyLookback} heights`);
+    
+    // Read-only: no wallet needed
+    const provider = new hre.ethers.JsonRpcProvider(config.rpcUrl);
+    const WrappedMonero = await hre.ethers.getContractFactory('WrappedMonero');
+    const contract = WrappedMonero.attach(config.bridgeAddress).connect(provider);
+    
+    console.log('\n✅ Verifier ready!\n');
+    console.log('═'.repeat(70));
+    
+    const metrics = createVerifyMetrics(config.verifyRpcUrls);
+    
+    async function poll() {
+        if (shutdown.requested) {
+            return;
+        }
+        
+        try {
+            console.log(`\n[${new Date().toISOString()}] 🔍 Verifying posted blocks...`);
+            
+            const latestPosted = Number(await contract.latestMoneroBlock());
+            const heights = sampleHeights(latestPosted, config.verifyLookback, config.verifySamples);
+            
+            const results = [];
+            for (const height of heights) {
+                if (shutdown.requested) {
+                    break;
+                }
+                const result = await verifyHeight(contract, height, config.verifyRpcUrls);
+                for (const node of result.nodes) {
+                    if (node.error) {
+                        console.error(`   ⚠️  Block ${height} @ ${node.rpcUrl}: ${node.error}`);
+                    } else if (node.mismatches.length > 0) {
+                        console.error(`   ❌ Block ${height} @ ${node.rpcUrl} diverges from on-chain data: ${node.mismatches.join(', ')}`);
+                    }
+                }
+                results.push(result);
+            }
+            
+            recordVerifyResults(metrics, results);
+            
+            console.log(`   📊 Round ${metrics.rounds}: checked ${results.length} height(s), totals since start:`);
+            console.log(`      Unposted heights skipped: ${metrics.skipped}`);
+            for (const [rpcUrl, counters] of Object.entries(metrics.nodes)) {
+                console.log(`      ${rpcUrl}: ${counters.checked} checked, ${counters.matched} matched, ` +
+                    `${counters.diverged} diverged (hash ${counters.fields.blockHash}, ` +
+                    `tx root ${counters.fields.txMerkleRoot}, output root ${counters.fields.outputMerkleRoot}), ` +
+                    `${counters.errors} errors`);
+            }
+        } catch (error) {
+            console.error('\n❌ Error in verifier loop:', error.message);
+            console.error(error.stack);
+        }
+    }
+    
+    await startPolling(poll);
+}
+
+// How far the bridge lags the Monero tip and, optionally, where a deposit block stands.
+// `depositBlock` is moneroBlocks(depositHeight); all heights and times are numbers
+function bridgeSyncStatus({
+    moneroTipHeight,
+    latestPosted,
+    latestPostedAt,
+    nowSec,
+    maxLagBlocks,
+    depositHeight = null,
+    depositBlock = null,
+    maxAttestationAge = null,
+    attestationOverride = false,
+}) {
+    const lagBlocks = Math.max(0, moneroTipHeight - latestPosted);
+    const status = {
+        moneroTipHeight,
+        latestMoneroBlock: latestPosted,
+        lagBlocks,
+        secondsSinceLastPost: latestPostedAt > 0 ? nowSec - latestPostedAt : null,
+        stale: lagBlocks > maxLagBlocks,
+        deposit: null,
+    };
+    
+    if (depositHeight !== null) {
+        const posted = Boolean(depositBlock && depositBlock.exists);
+        const expiresAt = posted ? Number(depositBlock.timestamp) + maxAttestationAge : null;
+        status.deposit = {
+            height: depositHeight,
+            confirmations: Math.max(0, moneroTipHeight - depositHeight + 1),
+            posted,
+            attestationExpiresAt: expiresAt,
+            attestationExpired: posted && !attestationOverride && nowSec > expiresAt,
+        };
+    }
+    
+    return status;
+}
+
+// Read the live inputs of bridgeSyncStatus from the contract and the Monero node
+async function getBridgeSyncStatus(contract, { depositHeight = null, maxLagBlocks = 10 } = {}) {
+    const tip = await getMoneroBlockHeader();
+    const latestPosted = await contract.latestMoneroBlock();
+    const latestBlock = await contract.moneroBlocks(latestPosted);
+    
+    const depositInputs = {};
+    if (depositHeight !== null) {
+        depositInputs.depositHeight = depositHeight;
+        depositInputs.depositBlock = await contract.moneroBlocks(depositHeight);
+        depositInputs.maxAttestationAge = Number(await contract.maxAttestationAge());
+        depositInputs.attestationOverride = await contract.attestationAgeOverride(depositHeight);
+    }
+    
+    return bridgeSyncStatus({
+        moneroTipHeight: tip.height,
+        latestPosted: Number(latestPosted),
+        latestPostedAt: latestBlock.exists ? Number(latestBlock.timestamp) : 0,
+        nowSec: Math.floor(Date.now() / 1000),
+        maxLagBlocks,
+        ...depositInputs,
+    });
+}
+
+// Handle shutdown gracefully: let the in-flight poll settle before exiting
+function requestShutdown(signal) {
+    if (shutdown.requested) {
+        console.log(`\n\n⚠️  ${signal} received again, forcing exit`);
+        process.exit(1);
+    }
+    shutdown.requested = true;
+    
+    console.log(`\n\n👋 ${signal} received, oracle shutting down${shutdown.drain ? ' (draining pending blocks)' : ''}...`);
+    
+    if (pollTimer) {
+        clearTimeout(pollTimer);
+    }
+    
+    Promise.resolve(inFlightPoll).then(() => {
+        console.log('   ✅ Shutdown complete');
+        process.exit(0);
+    });
 }
 
-// Handle shutdown gracefully
-process.on('SIGINT', () => {
-    console.log('\n\n👋 Oracle shutting down...');
-    process.exit(0);
-});
-
-process.on('SIGTERM', () => {
-    console.log('\n\n👋 Oracle shutting down...');
-    process.exit(0);
-});
-
-// Start oracle
-runOracle().catch(error => {
-    console.error('❌ Fatal error:', error);
-    process.exit(1);
-});
+module.exports = {
+    bridgeSyncStatus,
+    getBridgeSyncStatus,
+    ONCHAIN_MAX_FUTURE_DRIFT_SEC,
+    postBlock,
+    postPendingBlocks,
+    createVerifyMetrics,
+    diffStoredBlock,
+    recordVerifyResults,
+    sampleHeights,
+    verifyHeight,
+    TIMESTAMP_CHECK_WINDOW,
+    HeaderError,
+    cumulativeDifficulty,
+    median,
+    validateBlockHeader,
+    validateOnChainLink,
+};
+
+// Start oracle when run directly (not when required by tests)
+if (require.main === module) {
+    validateConfig();
+    
+    process.on('SIGINT', () => requestShutdown('SIGINT'));
+    process.on('SIGTERM', () => requestShutdown('SIGTERM'));
+    
+    (verifyMode ? runVerifier() : runOracle()).catch(error => {
+        console.error('❌ Fatal error:', error);
+        process.exit(1);
+    });
+}
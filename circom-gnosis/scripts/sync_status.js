@@ -0,0 +1,46 @@
+const hre = require("hardhat");
+const fs = require("fs");
+const { getBridgeSyncStatus } = require("../oracle/monero-oracle");
+
+// Usage: [DEPOSIT_HEIGHT=<monero height>] npx hardhat run scripts/sync_status.js --network <network>
+async function main() {
+    console.log("🔄 Bridge Sync Status\n");
+    console.log("═".repeat(70));
+
+    const deployment = JSON.parse(fs.readFileSync('oracle/deployment.json', 'utf8'));
+    const bridgeAddress = process.env.BRIDGE_ADDRESS || deployment.bridge;
+    const bridge = await hre.ethers.getContractAt("WrappedMonero", bridgeAddress);
+    console.log("\n📋 Contract:", bridgeAddress);
+
+    const depositHeight = process.env.DEPOSIT_HEIGHT ? parseInt(process.env.DEPOSIT_HEIGHT) : null;
+    const maxLagBlocks = parseInt(process.env.SYNC_MAX_LAG_BLOCKS || '10');
+
+    const status = await getBridgeSyncStatus(bridge, { depositHeight, maxLagBlocks });
+
+    console.log("\n⛓️  Monero tip:", status.moneroTipHeight);
+    console.log("   Latest posted block:", status.latestMoneroBlock);
+    console.log("   Lag:", status.lagBlocks, "block(s)", status.stale ? `⚠️  STALE (> ${maxLagBlocks})` : "✅");
+    if (status.secondsSinceLastPost !== null) {
+        console.log("   Last post:", status.secondsSinceLastPost, "s ago");
+    }
+
+    if (status.deposit) {
+        const { deposit } = status;
+        console.log("\n💰 Deposit block", deposit.height);
+        console.log("   Confirmations:", deposit.confirmations);
+        console.log("   Posted on-chain:", deposit.posted ? "yes" : "no");
+        if (deposit.posted) {
+            console.log("   Attestation expires:", new Date(deposit.attestationExpiresAt * 1000).toISOString(),
+                deposit.attestationExpired ? "❌ EXPIRED" : "✅");
+        }
+    }
+
+    console.log("\n" + JSON.stringify(status, null, 2));
+}
+
+main()
+    .then(() => process.exit(0))
+    .catch((error) => {
+        console.error(error);
+        process.exit(1);
+    });
@@ -0,0 +1,528 @@
+const { expect } = require('chai');
+const {
+    TIMESTAMP_CHECK_WINDOW,
+    bridgeSyncStatus,
+    HeaderError,
+    cumulativeDifficulty,
+    median,
+    createVerifyMetrics,
+    diffStoredBlock,
+    postBlock,
+    postPendingBlocks,
+    recordVerifyResults,
+    sampleHeights,
+    verifyHeight,
+    validateBlockHeader,
+    validateOnChainLink,
+} = require('../oracle/monero-oracle');
+
+const NOW = 1_700_000_000;
+const DRIFT = 7200;
+
+function hashFor(height) {
+    return height.toString(16).padStart(64, '0');
+}
+
+// Build `count` linked headers starting at `startHeight`, timestamps startTime, startTime + 1, ...
+function buildChain(count, startHeight = 1000, startTime = 1000) {
+    const headers = [];
+    for (let i = 0; i < count; i++) {
+        const height = startHeight + i;
+        headers.push({
+            height,
+            hash: hashFor(height),
+            prev_hash: hashFor(height - 1),
+            timestamp: startTime + i,
+            cumulative_difficulty: (i + 1) * 100,
+        });
+    }
+    return headers;
+}
+
+// Resolve to the error `promise` rejects with, failing if it resolves
+async function rejectionOf(promise) {
+    try {
+        await promise;
+    } catch (error) {
+        return error;
+    }
+    throw new Error('Expected promise to reject');
+}
+
+// Contract stand-in whose post reverts as a replay and returns `stored` for moneroBlocks(h)
+function replayedContract(stored, revertReason = 'Height must increase') {
+    return {
+        async postMoneroBlock() {
+            throw new Error(`execution reverted: "${revertReason}"`);
+        },
+        async moneroBlocks() {
+            return stored;
+        },
+    };
+}
+
+// Next header extending `chain`, with optional overrides
+function nextHeader(chain, overrides = {}) {
+    const prev = chain[chain.length - 1];
+    return {
+        height: prev.height + 1,
+        hash: hashFor(prev.height + 1),
+        prev_hash: prev.hash,
+        timestamp: prev.timestamp + 1,
+        cumulative_difficulty: prev.cumulative_difficulty + 100,
+        ...overrides,
+    };
+}
+
+describe('Monero oracle header validation', function () {
+    describe('median', function () {
+        it('returns the middle value of an odd-sized window', function () {
+            expect(median([5, 1, 3])).to.equal(3);
+        });
+
+        it('returns the floored average of the two middle values of an even-sized window', function () {
+            expect(median([4, 1, 2, 3])).to.equal(2);
+            expect(median([1, 2, 3, 5])).to.equal(2);
+        });
+
+        it('does not reorder its input', function () {
+            const values = [3, 1, 2];
+            median(values);
+            expect(values).to.deep.equal([3, 1, 2]);
+        });
+    });
+
+    describe('cumulativeDifficulty', function () {
+        it('falls back to the numeric cumulative_difficulty', function () {
+            expect(cumulativeDifficulty({ cumulative_difficulty: 12345 })).to.equal(12345n);
+        });
+
+        it('prefers wide_cumulative_difficulty beyond 2^53', function () {
+            const header = {
+                cumulative_difficulty: Number.MAX_SAFE_INTEGER,
+                wide_cumulative_difficulty: '0x100000000000000000',
+            };
+            expect(cumulativeDifficulty(header)).to.equal(2n ** 68n);
+        });
+    });
+
+    describe('validateBlockHeader', function () {
+        it('accepts a header extending a full window', function () {
+            const chain = buildChain(TIMESTAMP_CHECK_WINDOW);
+            expect(validateBlockHeader(nextHeader(chain), chain, NOW, DRIFT)).to.be.null;
+        });
+
+        it('accepts any in-drift header when there is no history', function () {
+            const [header] = buildChain(1);
+            expect(validateBlockHeader(header, [], NOW, DRIFT)).to.be.null;
+        });
+
+        it('accepts a timestamp exactly at now + drift', function () {
+            const chain = buildChain(1);
+            const header = nextHeader(chain, { timestamp: NOW + DRIFT });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT)).to.be.null;
+        });
+
+        it('rejects a timestamp beyond now + drift with TIMESTAMP_TOO_FAR_IN_FUTURE', function () {
+            const chain = buildChain(1);
+            const header = nextHeader(chain, { timestamp: NOW + DRIFT + 1 });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.TIMESTAMP_TOO_FAR_IN_FUTURE);
+        });
+
+        it('rejects a height gap with NON_SEQUENTIAL_HEIGHT', function () {
+            const chain = buildChain(5);
+            const header = nextHeader(chain, { height: chain[4].height + 2 });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.NON_SEQUENTIAL_HEIGHT);
+        });
+
+        it('rejects a broken link with PREV_HASH_MISMATCH', function () {
+            const chain = buildChain(5);
+            const header = nextHeader(chain, { prev_hash: 'ff'.repeat(32) });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.PREV_HASH_MISMATCH);
+        });
+
+        it('rejects non-increasing difficulty with DIFFICULTY_NOT_INCREASING', function () {
+            const chain = buildChain(5);
+            const header = nextHeader(chain, { cumulative_difficulty: chain[4].cumulative_difficulty });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.DIFFICULTY_NOT_INCREASING);
+        });
+
+        it('compares wide difficulty against a numeric parent difficulty', function () {
+            const chain = buildChain(5);
+            const lower = nextHeader(chain, { wide_cumulative_difficulty: '0x' + (400).toString(16) });
+            const higher = nextHeader(chain, { wide_cumulative_difficulty: '0x' + (600).toString(16) });
+            expect(validateBlockHeader(lower, chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.DIFFICULTY_NOT_INCREASING);
+            expect(validateBlockHeader(higher, chain, NOW, DRIFT)).to.be.null;
+        });
+
+        it('accepts a timestamp equal to the median-time-past', function () {
+            // Timestamps 1000..1059: median of an even window is floor((1029 + 1030) / 2)
+            const chain = buildChain(TIMESTAMP_CHECK_WINDOW);
+            const header = nextHeader(chain, { timestamp: 1029 });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT)).to.be.null;
+        });
+
+        it('rejects a timestamp below the median-time-past with TIMESTAMP_BELOW_MEDIAN', function () {
+            const chain = buildChain(TIMESTAMP_CHECK_WINDOW);
+            const header = nextHeader(chain, { timestamp: 1028 });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.TIMESTAMP_BELOW_MEDIAN);
+        });
+
+        it('skips the median rule while the window is shorter than 60 blocks', function () {
+            const chain = buildChain(TIMESTAMP_CHECK_WINDOW - 1);
+            const header = nextHeader(chain, { timestamp: 1 });
+            expect(validateBlockHeader(header, chain, NOW, DRIFT)).to.be.null;
+        });
+
+        it('uses only the most recent 60 headers for the median', function () {
+            // Timestamps 1000..1069: the last 60 are 1010..1069, median floor((1039 + 1040) / 2)
+            const chain = buildChain(TIMESTAMP_CHECK_WINDOW + 10);
+            expect(validateBlockHeader(nextHeader(chain, { timestamp: 1039 }), chain, NOW, DRIFT)).to.be.null;
+            expect(validateBlockHeader(nextHeader(chain, { timestamp: 1038 }), chain, NOW, DRIFT).code)
+                .to.equal(HeaderError.TIMESTAMP_BELOW_MEDIAN);
+        });
+    });
+
+    describe('validateOnChainLink', function () {
+        const [parent] = buildChain(1);
+        const header = nextHeader([parent]);
+
+        it('accepts any header when the parent was never posted', function () {
+            const stored = { blockHash: '0x' + '00'.repeat(32), exists: false };
+            expect(validateOnChainLink(header, stored)).to.be.null;
+        });
+
+        it('accepts a header linking to the stored parent hash regardless of case', function () {
+            const stored = { blockHash: '0x' + parent.hash.toUpperCase(), exists: true };
+            expect(validateOnChainLink(header, stored)).to.be.null;
+        });
+
+        it('rejects a header from a different fork with PREV_HASH_MISMATCH', function () {
+            const stored = { blockHash: '0x' + 'ab'.repeat(32), exists: true };
+            expect(validateOnChainLink(header, stored).code).to.equal(HeaderError.PREV_HASH_MISMATCH);
+        });
+    });
+
+    describe('postBlock replay handling', function () {
+        const HEIGHT = 1001;
+        const HASH = '0x' + 'aa'.repeat(32);
+        const TX_ROOT = '0x' + 'bb'.repeat(32);
+        const OUTPUT_ROOT = '0x' + 'cc'.repeat(32);
+        const HEADER = { timestamp: 1000, cumulative_difficulty: 100 };
+
+        function stored(overrides = {}) {
+            return {
+                blockHash: HASH,
+                txMerkleRoot: TX_ROOT,
+                outputMerkleRoot: OUTPUT_ROOT,
+                exists: true,
+                ...overrides,
+            };
+        }
+
+        function replay(contract) {
+            return postBlock(contract, HEIGHT, HASH, TX_ROOT, OUTPUT_ROOT, HEADER);
+        }
+
+        it('skips a replay whose stored block matches, regardless of case', async function () {
+            const contract = replayedContract(stored({ blockHash: HASH.toUpperCase().replace('0X', '0x') }));
+            expect(await replay(contract)).to.be.null;
+        });
+
+        it('throws when the stored block hash differs', async function () {
+            const error = await rejectionOf(replay(replayedContract(stored({ blockHash: '0x' + 'dd'.repeat(32) }))));
+            expect(error.message).to.include('conflicts with on-chain data');
+        });
+
+        it('throws when a stored Merkle root differs', async function () {
+            const txError = await rejectionOf(replay(replayedContract(stored({ txMerkleRoot: '0x' + 'dd'.repeat(32) }))));
+            expect(txError.message).to.include('conflicts with on-chain data');
+
+            const outputError = await rejectionOf(replay(replayedContract(stored({ outputMerkleRoot: '0x' + 'dd'.repeat(32) }))));
+            expect(outputError.message).to.include('conflicts with on-chain data');
+        });
+
+        it('throws when no block is stored at the height', async function () {
+            const error = await rejectionOf(replay(replayedContract(stored({ exists: false }))));
+            expect(error.message).to.include('conflicts with on-chain data');
+        });
+
+        it('rethrows other reverts unchanged', async function () {
+            const contract = replayedContract(stored(), 'Difficulty must increase');
+            const error = await rejectionOf(replay(contract));
+            expect(error.message).to.include('Difficulty must increase');
+        });
+    });
+
+    describe('postPendingBlocks shutdown', function () {
+        const chain = buildChain(4);
+        const [parent, ...pending] = chain;
+        const storedParent = { blockHash: '0x' + parent.hash, exists: true };
+
+        // Run the loop over `pending`, calling `onPost` after each post
+        async function run(shutdownState, onPost = () => {}) {
+            const posted = [];
+            const result = await postPendingBlocks({}, {
+                fromHeight: pending[0].height,
+                toHeight: pending[pending.length - 1].height,
+                storedParent,
+                recentHeaders: [parent],
+            }, {
+                fetchBlock: async height => ({
+                    json: JSON.stringify({ tx_hashes: [] }),
+                    block_header: chain.find(header => header.height === height),
+                }),
+                fetchOutputs: async () => [],
+                post: async (contract, height) => {
+                    posted.push(height);
+                    onPost(height);
+                    return null;
+                },
+                shutdownState,
+            });
+            return { posted, result };
+        }
+
+        it('posts every pending block when no shutdown is requested', async function () {
+            const { posted, result } = await run({ requested: false, drain: false });
+            expect(posted).to.deep.equal(pending.map(header => header.height));
+            expect(result.lastPostedBlock).to.equal(pending[pending.length - 1].height);
+            expect(result.recentHeaders.length).to.equal(chain.length);
+        });
+
+        it('stops between blocks when shutdown is requested without drain', async function () {
+            const shutdownState = { requested: false, drain: false };
+            const { posted, result } = await run(shutdownState, () => { shutdownState.requested = true; });
+            expect(posted).to.deep.equal([pending[0].height]);
+            expect(result.lastPostedBlock).to.equal(pending[0].height);
+        });
+
+        it('posts nothing when shutdown was already requested', async function () {
+            const { posted, result } = await run({ requested: true, drain: false });
+            expect(posted).to.deep.equal([]);
+            expect(result.lastPostedBlock).to.be.null;
+        });
+
+        it('keeps posting to the last pending block when draining', async function () {
+            const shutdownState = { requested: false, drain: true };
+            const { posted } = await run(shutdownState, () => { shutdownState.requested = true; });
+            expect(posted).to.deep.equal(pending.map(header => header.height));
+        });
+    });
+
+    describe('verify mode', function () {
+        const ZERO_ROOT = '0x' + '00'.repeat(32);
+        const HASH = 'ab'.repeat(32);
+        const NODE_A = 'http://node-a';
+        const NODE_B = 'http://node-b';
+
+        // Stored empty block with hash HASH; both Merkle roots of a block without txs are zero
+        function storedContract(overrides = {}) {
+            return {
+                async moneroBlocks() {
+                    return {
+                        blockHash: '0x' + HASH,
+                        txMerkleRoot: ZERO_ROOT,
+                        outputMerkleRoot: ZERO_ROOT,
+                        exists: true,
+                        ...overrides,
+                    };
+                },
+            };
+        }
+
+        // Nodes serving an empty block, with per-node hashes and failures
+        function nodeDeps({ hashes = {}, failing = [] } = {}) {
+            return {
+                fetchBlock: async (height, rpcUrl) => {
+                    if (failing.includes(rpcUrl)) {
+                        throw new Error('connect ECONNREFUSED');
+                    }
+                    return { json: JSON.stringify({ tx_hashes: [] }), block_header: { hash: hashes[rpcUrl] || HASH } };
+                },
+                fetchOutputs: async () => [],
+            };
+        }
+
+        describe('diffStoredBlock', function () {
+            const block = { blockHash: '0xAA', txMerkleRoot: '0xbb', outputMerkleRoot: '0xcc' };
+
+            it('returns no fields when everything matches regardless of case', function () {
+                const stored = { blockHash: '0xaa', txMerkleRoot: '0xBB', outputMerkleRoot: '0xcc' };
+                expect(diffStoredBlock(stored, block)).to.deep.equal([]);
+            });
+
+            it('returns every differing field', function () {
+                const stored = { blockHash: '0xaa', txMerkleRoot: '0xdd', outputMerkleRoot: '0xee' };
+                expect(diffStoredBlock(stored, block)).to.deep.equal(['txMerkleRoot', 'outputMerkleRoot']);
+            });
+        });
+
+        describe('sampleHeights', function () {
+            // Deterministic stand-in for Math.random cycling through `values`
+            function sequence(values) {
+                let i = 0;
+                return () => values[i++ % values.length];
+            }
+
+            it('returns distinct sorted heights within the lookback window', function () {
+                const heights = sampleHeights(1000, 10, 3, sequence([0.95, 0.0, 0.5, 0.0]));
+                expect(heights).to.deep.equal([991, 996, 1000]);
+            });
+
+            it('returns the whole window when it is smaller than the sample count', function () {
+                const heights = sampleHeights(3, 10, 5, sequence([0.0, 0.4, 0.9]));
+                expect(heights).to.deep.equal([1, 2, 3]);
+            });
+        });
+
+        describe('verifyHeight', function () {
+            it('reports no mismatches when every node agrees with the stored block', async function () {
+                const result = await verifyHeight(storedContract(), 7, [NODE_A, NODE_B], nodeDeps());
+                expect(result.exists).to.equal(true);
+                expect(result.nodes).to.deep.equal([
+                    { rpcUrl: NODE_A, mismatches: [], error: null },
+                    { rpcUrl: NODE_B, mismatches: [], error: null },
+                ]);
+            });
+
+            it('reports the diverging node and field', async function () {
+                const deps = nodeDeps({ hashes: { [NODE_B]: 'cd'.repeat(32) } });
+                const result = await verifyHeight(storedContract(), 7, [NODE_A, NODE_B], deps);
+                expect(result.nodes[0].mismatches).to.deep.equal([]);
+                expect(result.nodes[1].mismatches).to.deep.equal(['blockHash']);
+            });
+
+            it('reports recomputed root mismatches', async function () {
+                const contract = storedContract({ txMerkleRoot: '0x' + '11'.repeat(32) });
+                const result = await verifyHeight(contract, 7, [NODE_A], nodeDeps());
+                expect(result.nodes[0].mismatches).to.deep.equal(['txMerkleRoot']);
+            });
+
+            it('records node failures as errors rather than divergence', async function () {
+                const result = await verifyHeight(storedContract(), 7, [NODE_A], nodeDeps({ failing: [NODE_A] }));
+                expect(result.nodes[0].mismatches).to.deep.equal([]);
+                expect(result.nodes[0].error).to.equal('connect ECONNREFUSED');
+            });
+
+            it('skips heights that were never posted', async function () {
+                const result = await verifyHeight(storedContract({ exists: false }), 7, [NODE_A], nodeDeps());
+                expect(result).to.deep.equal({ height: 7, exists: false, nodes: [] });
+            });
+        });
+
+        describe('recordVerifyResults', function () {
+            it('accumulates per-node counters across rounds', function () {
+                const metrics = createVerifyMetrics([NODE_A, NODE_B]);
+                recordVerifyResults(metrics, [
+                    { height: 1, exists: false, nodes: [] },
+                    {
+                        height: 2,
+                        exists: true,
+                        nodes: [
+                            { rpcUrl: NODE_A, mismatches: [], error: null },
+                            { rpcUrl: NODE_B, mismatches: ['blockHash', 'outputMerkleRoot'], error: null },
+                        ],
+                    },
+                ]);
+                recordVerifyResults(metrics, [
+                    {
+                        height: 3,
+                        exists: true,
+                        nodes: [
+                            { rpcUrl: NODE_A, mismatches: [], error: 'timeout of 20000ms exceeded' },
+                            { rpcUrl: NODE_B, mismatches: ['outputMerkleRoot'], error: null },
+                        ],
+                    },
+                ]);
+
+                expect(metrics.rounds).to.equal(2);
+                expect(metrics.skipped).to.equal(1);
+                expect(metrics.nodes[NODE_A]).to.deep.equal({
+                    checked: 2,
+                    matched: 1,
+                    diverged: 0,
+                    errors: 1,
+                    fields: { blockHash: 0, txMerkleRoot: 0, outputMerkleRoot: 0 },
+                });
+                expect(metrics.nodes[NODE_B]).to.deep.equal({
+                    checked: 2,
+                    matched: 0,
+                    diverged: 2,
+                    errors: 0,
+                    fields: { blockHash: 1, txMerkleRoot: 0, outputMerkleRoot: 2 },
+                });
+            });
+        });
+    });
+
+    describe('bridgeSyncStatus', function () {
+        const BASE = {
+            moneroTipHeight: 1010,
+            latestPosted: 1005,
+            latestPostedAt: NOW - 300,
+            nowSec: NOW,
+            maxLagBlocks: 10,
+        };
+        const DAY = 24 * 3600;
+
+        it('reports lag and time since the last post', function () {
+            expect(bridgeSyncStatus(BASE)).to.deep.equal({
+                moneroTipHeight: 1010,
+                latestMoneroBlock: 1005,
+                lagBlocks: 5,
+                secondsSinceLastPost: 300,
+                stale: false,
+                deposit: null,
+            });
+        });
+
+        it('flags the bridge as stale beyond maxLagBlocks', function () {
+            expect(bridgeSyncStatus({ ...BASE, moneroTipHeight: 1015 }).stale).to.equal(false);
+            expect(bridgeSyncStatus({ ...BASE, moneroTipHeight: 1016 }).stale).to.equal(true);
+        });
+
+        it('has no last post time before the first post after deployment', function () {
+            expect(bridgeSyncStatus({ ...BASE, latestPostedAt: 0 }).secondsSinceLastPost).to.be.null;
+        });
+
+        it('counts deposit confirmations including the deposit block', function () {
+            const status = bridgeSyncStatus({
+                ...BASE,
+                depositHeight: 1008,
+                depositBlock: { exists: false, timestamp: 0n },
+                maxAttestationAge: DAY,
+            });
+            expect(status.deposit).to.deep.equal({
+                height: 1008,
+                confirmations: 3,
+                posted: false,
+                attestationExpiresAt: null,
+                attestationExpired: false,
+            });
+        });
+
+        it('reports when a posted deposit block is too old to mint against', function () {
+            const postedAt = NOW - DAY - 1;
+            const inputs = {
+                ...BASE,
+                depositHeight: 1001,
+                depositBlock: { exists: true, timestamp: BigInt(postedAt) },
+                maxAttestationAge: DAY,
+            };
+            const status = bridgeSyncStatus(inputs);
+            expect(status.deposit.posted).to.equal(true);
+            expect(status.deposit.attestationExpiresAt).to.equal(postedAt + DAY);
+            expect(status.deposit.attestationExpired).to.equal(true);
+
+            expect(bridgeSyncStatus({ ...inputs, attestationOverride: true }).deposit.attestationExpired)
+                .to.equal(false);
+        });
+    });
+});
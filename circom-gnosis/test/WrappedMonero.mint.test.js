@@ -0,0 +1,125 @@
+const { expect } = require('chai');
+const { ethers } = require('hardhat');
+const { loadFixture, time } = require('@nomicfoundation/hardhat-network-helpers');
+
+const INITIAL_BLOCK = 3_597_142n;
+const HEIGHT = INITIAL_BLOCK + 1n;
+const ZERO = ethers.ZeroHash;
+
+describe('WrappedMonero mint attestation age', function () {
+    async function deployFixture() {
+        const [oracle, lp, user] = await ethers.getSigners();
+
+        const MockPyth = await ethers.getContractFactory('MockPyth');
+        const pyth = await MockPyth.deploy(15_000_000_000n, -8); // $150
+
+        // Verifier, DAI and sDAI are only reached after the Merkle checks
+        const WrappedMonero = await ethers.getContractFactory('WrappedMonero');
+        const bridge = await WrappedMonero.deploy(
+            ethers.Wallet.createRandom().address,
+            ethers.Wallet.createRandom().address,
+            ethers.Wallet.createRandom().address,
+            await pyth.getAddress(),
+            INITIAL_BLOCK
+        );
+
+        await bridge.connect(lp).registerLP(100, 100, '5monero-address', true);
+        await bridge.postMoneroBlock(
+            HEIGHT,
+            ethers.id('block'),
+            ethers.id('tx-root'),
+            ethers.id('output-root'),
+            await time.latest(),
+            1000n
+        );
+
+        return { bridge, oracle, lp, user };
+    }
+
+    // Mint against `blockHeight` with placeholder proofs; the tx is not in the posted
+    // tree, so a mint that passes the age check reverts with 'TX not in block'
+    function mint(bridge, lp, user, blockHeight = HEIGHT) {
+        return bridge.connect(user).mint(
+            new Array(24).fill(0n),
+            new Array(70).fill(0n),
+            { c: ZERO, s: ZERO, K1: ZERO, K2: ZERO },
+            {
+                R_x: ZERO, R_y: ZERO, S_x: ZERO, S_y: ZERO, P_x: ZERO, P_y: ZERO,
+                B_x: ZERO, B_y: ZERO, G_x: ZERO, G_y: ZERO, A_x: ZERO, A_y: ZERO,
+            },
+            { txHash: ethers.id('tx'), outputIndex: 0n, ecdhAmount: ZERO, outputPubKey: ZERO, commitment: ZERO },
+            blockHeight,
+            [],
+            0n,
+            [],
+            0n,
+            user.address,
+            lp.address,
+            []
+        );
+    }
+
+    it('defaults to DEFAULT_MAX_ATTESTATION_AGE', async function () {
+        const { bridge } = await loadFixture(deployFixture);
+        expect(await bridge.maxAttestationAge()).to.equal(await bridge.DEFAULT_MAX_ATTESTATION_AGE());
+    });
+
+    it('accepts a block exactly maxAttestationAge old', async function () {
+        const { bridge, lp, user } = await loadFixture(deployFixture);
+        const postedAt = (await bridge.moneroBlocks(HEIGHT)).timestamp;
+        await time.setNextBlockTimestamp(postedAt + (await bridge.maxAttestationAge()));
+        await expect(mint(bridge, lp, user)).to.be.revertedWith('TX not in block');
+    });
+
+    it('rejects a block older than maxAttestationAge', async function () {
+        const { bridge, lp, user } = await loadFixture(deployFixture);
+        const postedAt = (await bridge.moneroBlocks(HEIGHT)).timestamp;
+        await time.setNextBlockTimestamp(postedAt + (await bridge.maxAttestationAge()) + 1n);
+        await expect(mint(bridge, lp, user)).to.be.revertedWith('Attestation too old');
+    });
+
+    it('accepts an old block once the oracle overrides its height', async function () {
+        const { bridge, lp, user } = await loadFixture(deployFixture);
+        await time.increase((await bridge.maxAttestationAge()) + 1n);
+
+        await expect(bridge.setAttestationAgeOverride(HEIGHT, true))
+            .to.emit(bridge, 'AttestationAgeOverrideSet')
+            .withArgs(HEIGHT, true);
+        await expect(mint(bridge, lp, user)).to.be.revertedWith('TX not in block');
+
+        await bridge.setAttestationAgeOverride(HEIGHT, false);
+        await expect(mint(bridge, lp, user)).to.be.revertedWith('Attestation too old');
+    });
+
+    it('applies a raised maxAttestationAge to existing blocks', async function () {
+        const { bridge, lp, user } = await loadFixture(deployFixture);
+        const maxAge = await bridge.maxAttestationAge();
+        await time.increase(maxAge + 1n);
+
+        await expect(bridge.setMaxAttestationAge(maxAge * 2n))
+            .to.emit(bridge, 'MaxAttestationAgeUpdated')
+            .withArgs(maxAge * 2n);
+        await expect(mint(bridge, lp, user)).to.be.revertedWith('TX not in block');
+    });
+
+    it('rejects a maxAttestationAge below MIN_MAX_ATTESTATION_AGE', async function () {
+        const { bridge } = await loadFixture(deployFixture);
+        const min = await bridge.MIN_MAX_ATTESTATION_AGE();
+        await expect(bridge.setMaxAttestationAge(min - 1n)).to.be.revertedWith('Max age too short');
+        await expect(bridge.setMaxAttestationAge(min)).to.emit(bridge, 'MaxAttestationAgeUpdated');
+    });
+
+    it('restricts both settings to the oracle', async function () {
+        const { bridge, user } = await loadFixture(deployFixture);
+        await expect(bridge.connect(user).setMaxAttestationAge(2n * 24n * 3600n))
+            .to.be.revertedWith('Only oracle');
+        await expect(bridge.connect(user).setAttestationAgeOverride(HEIGHT, true))
+            .to.be.revertedWith('Only oracle');
+    });
+
+    it('only overrides posted blocks', async function () {
+        const { bridge } = await loadFixture(deployFixture);
+        await expect(bridge.setAttestationAgeOverride(HEIGHT + 1n, true))
+            .to.be.revertedWith('Block not posted');
+    });
+});
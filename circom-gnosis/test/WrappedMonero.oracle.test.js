@@ -0,0 +1,207 @@
+const { expect } = require('chai');
+const { ethers } = require('hardhat');
+const { loadFixture, time } = require('@nomicfoundation/hardhat-network-helpers');
+const { ONCHAIN_MAX_FUTURE_DRIFT_SEC } = require('../oracle/monero-oracle');
+
+const INITIAL_BLOCK = 3_597_142n;
+const TWO_HOURS = 2n * 60n * 60n;
+const TX_ROOT = ethers.id('tx-root');
+const OUTPUT_ROOT = ethers.id('output-root');
+
+function blockHash(height) {
+    return ethers.keccak256(ethers.toBeHex(height, 32));
+}
+
+describe('WrappedMonero postMoneroBlock', function () {
+    async function deployFixture() {
+        const [oracle, other] = await ethers.getSigners();
+
+        const MockPyth = await ethers.getContractFactory('MockPyth');
+        const pyth = await MockPyth.deploy(15_000_000_000n, -8); // $150
+
+        // Verifier, DAI and sDAI are not touched by postMoneroBlock
+        const WrappedMonero = await ethers.getContractFactory('WrappedMonero');
+        const bridge = await WrappedMonero.deploy(
+            ethers.Wallet.createRandom().address,
+            ethers.Wallet.createRandom().address,
+            ethers.Wallet.createRandom().address,
+            await pyth.getAddress(),
+            INITIAL_BLOCK
+        );
+
+        return { bridge, oracle, other };
+    }
+
+    // Post `height` with timestamp/difficulty defaults that always pass
+    async function post(bridge, height, { moneroTimestamp, difficulty } = {}) {
+        const ts = moneroTimestamp ?? BigInt(await time.latest());
+        const diff = difficulty ?? (height - INITIAL_BLOCK) * 1000n;
+        return bridge.postMoneroBlock(height, blockHash(height), TX_ROOT, OUTPUT_ROOT, ts, diff);
+    }
+
+    describe('first post after deployment', function () {
+        it('starts from zero stored difficulty at the initial block', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const initial = await bridge.moneroBlocks(INITIAL_BLOCK);
+            expect(initial.exists).to.equal(false);
+            expect(initial.cumulativeDifficulty).to.equal(0n);
+        });
+
+        it('accepts any positive difficulty', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            await expect(post(bridge, INITIAL_BLOCK + 1n, { difficulty: 1n }))
+                .to.emit(bridge, 'MoneroBlockPosted')
+                .withArgs(INITIAL_BLOCK + 1n, blockHash(INITIAL_BLOCK + 1n));
+            expect(await bridge.latestMoneroBlock()).to.equal(INITIAL_BLOCK + 1n);
+        });
+
+        it('rejects zero difficulty', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            await expect(post(bridge, INITIAL_BLOCK + 1n, { difficulty: 0n }))
+                .to.be.revertedWith('Difficulty must increase');
+        });
+    });
+
+    describe('stored block data', function () {
+        it('returns the fields in declaration order', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const height = INITIAL_BLOCK + 1n;
+            await post(bridge, height, { difficulty: 4242n });
+            const postedAt = BigInt(await time.latest());
+
+            const stored = await bridge.moneroBlocks(height);
+            expect(stored.length).to.equal(6);
+            expect(stored[0]).to.equal(blockHash(height));  // blockHash
+            expect(stored[1]).to.equal(TX_ROOT);            // txMerkleRoot
+            expect(stored[2]).to.equal(OUTPUT_ROOT);        // outputMerkleRoot
+            expect(stored[3]).to.equal(postedAt);           // timestamp (chain time of post)
+            expect(stored[4]).to.equal(4242n);              // cumulativeDifficulty
+            expect(stored[5]).to.equal(true);               // exists
+            expect(stored.cumulativeDifficulty).to.equal(4242n);
+        });
+    });
+
+    describe('access and height', function () {
+        it('rejects non-oracle callers', async function () {
+            const { bridge, other } = await loadFixture(deployFixture);
+            await expect(post(bridge.connect(other), INITIAL_BLOCK + 1n))
+                .to.be.revertedWith('Only oracle');
+        });
+
+        it('rejects non-increasing heights', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            await post(bridge, INITIAL_BLOCK + 1n);
+            await expect(post(bridge, INITIAL_BLOCK + 1n, { difficulty: 10n ** 9n }))
+                .to.be.revertedWith('Height must increase');
+        });
+    });
+
+    describe('cumulative difficulty', function () {
+        it('rejects equal difficulty', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            await post(bridge, INITIAL_BLOCK + 1n, { difficulty: 500n });
+            await expect(post(bridge, INITIAL_BLOCK + 2n, { difficulty: 500n }))
+                .to.be.revertedWith('Difficulty must increase');
+        });
+
+        it('rejects lower difficulty', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            await post(bridge, INITIAL_BLOCK + 1n, { difficulty: 500n });
+            await expect(post(bridge, INITIAL_BLOCK + 2n, { difficulty: 499n }))
+                .to.be.revertedWith('Difficulty must increase');
+        });
+
+        it('compares against the latest posted block across height gaps', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            await post(bridge, INITIAL_BLOCK + 1n, { difficulty: 500n });
+            await expect(post(bridge, INITIAL_BLOCK + 10n, { difficulty: 500n }))
+                .to.be.revertedWith('Difficulty must increase');
+            await expect(post(bridge, INITIAL_BLOCK + 10n, { difficulty: 501n }))
+                .to.emit(bridge, 'MoneroBlockPosted');
+        });
+    });
+
+    describe('future drift', function () {
+        it('matches the oracle-side drift limit', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            expect(await bridge.MAX_MONERO_FUTURE_DRIFT()).to.equal(BigInt(ONCHAIN_MAX_FUTURE_DRIFT_SEC));
+        });
+
+        it('accepts a timestamp exactly 2 hours ahead of block.timestamp', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const next = BigInt(await time.latest()) + 100n;
+            await time.setNextBlockTimestamp(next);
+            await expect(post(bridge, INITIAL_BLOCK + 1n, { moneroTimestamp: next + TWO_HOURS }))
+                .to.emit(bridge, 'MoneroBlockPosted');
+        });
+
+        it('rejects a timestamp more than 2 hours ahead of block.timestamp', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const next = BigInt(await time.latest()) + 100n;
+            await time.setNextBlockTimestamp(next);
+            await expect(post(bridge, INITIAL_BLOCK + 1n, { moneroTimestamp: next + TWO_HOURS + 1n }))
+                .to.be.revertedWith('Timestamp too far in future');
+        });
+    });
+
+    describe('timestamp lower bound', function () {
+        // Post a consecutive run of `count` blocks with timestamps base, base + 1, ...
+        async function postRun(bridge, count, base) {
+            for (let i = 1n; i <= count; i++) {
+                await post(bridge, INITIAL_BLOCK + i, { moneroTimestamp: base + i - 1n });
+            }
+        }
+
+        it('is disabled until a full window of consecutive heights is posted', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const window = await bridge.MONERO_TIMESTAMP_WINDOW();
+            const base = BigInt(await time.latest()) - 100_000n;
+
+            await postRun(bridge, window - 1n, base);
+            expect(await bridge.moneroTimestampFloor(INITIAL_BLOCK + window)).to.equal(0n);
+            await expect(post(bridge, INITIAL_BLOCK + window, { moneroTimestamp: 1n }))
+                .to.emit(bridge, 'MoneroBlockPosted');
+        });
+
+        it('rejects a timestamp below the minimum of the window', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const window = await bridge.MONERO_TIMESTAMP_WINDOW();
+            const base = BigInt(await time.latest()) - 100_000n;
+
+            await postRun(bridge, window, base);
+            const next = INITIAL_BLOCK + window + 1n;
+            expect(await bridge.moneroTimestampFloor(next)).to.equal(base);
+
+            await expect(post(bridge, next, { moneroTimestamp: base - 1n }))
+                .to.be.revertedWith('Timestamp below recent minimum');
+            await expect(post(bridge, next, { moneroTimestamp: base }))
+                .to.emit(bridge, 'MoneroBlockPosted');
+        });
+
+        it('slides with the window as new heights overwrite old ones', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const window = await bridge.MONERO_TIMESTAMP_WINDOW();
+            const base = BigInt(await time.latest()) - 100_000n;
+
+            // Heights INITIAL+1..INITIAL+window+1 with timestamps base..base+window
+            await postRun(bridge, window + 1n, base);
+            const next = INITIAL_BLOCK + window + 2n;
+            expect(await bridge.moneroTimestampFloor(next)).to.equal(base + 1n);
+            await expect(post(bridge, next, { moneroTimestamp: base }))
+                .to.be.revertedWith('Timestamp below recent minimum');
+        });
+
+        it('is disabled again after a height gap', async function () {
+            const { bridge } = await loadFixture(deployFixture);
+            const window = await bridge.MONERO_TIMESTAMP_WINDOW();
+            const base = BigInt(await time.latest()) - 100_000n;
+
+            await postRun(bridge, window, base);
+            const afterGap = INITIAL_BLOCK + window + 5n;
+            expect(await bridge.moneroTimestampFloor(afterGap)).to.equal(0n);
+            await expect(post(bridge, afterGap, { moneroTimestamp: 1n }))
+                .to.emit(bridge, 'MoneroBlockPosted');
+            expect(await bridge.consecutiveMoneroBlocks()).to.equal(1n);
+        });
+    });
+});